@@ -1,6 +1,11 @@
 import { NextRequest, NextResponse } from 'next/server'
 import { createServerSupabaseClient } from '@/lib/supabase/server'
-import { extractText, getSupportedExtensions, getSupportedMimeTypes } from '@/lib/extractors'
+import {
+  extractText,
+  getSupportedExtensions,
+  getSupportedMimeTypes,
+  getUnusableFileError,
+} from '@/lib/extractors'
 import { parseChordChartText } from '@/lib/charts/import/parse'
 import { matchParsedLinesToSlides } from '@/lib/charts/import/match'
 
@@ -119,6 +124,14 @@ export async function POST(request: NextRequest) {
       )
     }
 
+    const unusableFileError = getUnusableFileError(file.name, file.size)
+    if (unusableFileError) {
+      return NextResponse.json(
+        { error: unusableFileError.message, code: unusableFileError.code },
+        { status: 400 }
+      )
+    }
+
     const arrayBuffer = await file.arrayBuffer()
     const buffer = Buffer.from(arrayBuffer)
 
@@ -2,11 +2,19 @@ import { NextRequest, NextResponse } from 'next/server'
 import JSZip from 'jszip'
 import { createServerSupabaseClient } from '@/lib/supabase/server'
 import { getSongSlides } from '@/lib/actions/song-arrangements'
-import { buildLyricsText, lyricsToDocx, lyricsToPdf, lyricsToRtf, lyricsToTxt } from '@/lib/exports/lyrics'
+import {
+  buildLyricsText,
+  lyricsToCsv,
+  lyricsToDocx,
+  lyricsToPdf,
+  lyricsToRtf,
+  lyricsToTxt,
+  type LyricsCsvSong,
+} from '@/lib/exports/lyrics'
 
 export const runtime = 'nodejs'
 
-type LyricsFormat = 'txt' | 'docx' | 'pdf' | 'rtf'
+type LyricsFormat = 'txt' | 'docx' | 'pdf' | 'rtf' | 'csv'
 
 function sanitizeFilename(value: string) {
   return value.replace(/[^a-zA-Z0-9\s-]/g, '').trim()
@@ -31,7 +39,7 @@ export async function POST(
     const formats = Array.isArray(body?.formats) ? body.formats : []
     const songIds = Array.isArray(body?.songIds) ? body.songIds.filter((id: unknown) => typeof id === 'string') : []
     const requestedFormats = formats.filter((format: unknown) =>
-      format === 'txt' || format === 'docx' || format === 'pdf' || format === 'rtf'
+      format === 'txt' || format === 'docx' || format === 'pdf' || format === 'rtf' || format === 'csv'
     ) as LyricsFormat[]
     if (requestedFormats.length === 0) {
       return NextResponse.json({ error: 'No formats selected' }, { status: 400 })
@@ -72,6 +80,26 @@ export async function POST(
     const zip = new JSZip()
     const singleSong = setSongs.length === 1
     const singleFormat = requestedFormats.length === 1
+    const includeCsv = requestedFormats.includes('csv')
+    const perSongFormats = requestedFormats.filter((format) => format !== 'csv')
+    const csvSongs: LyricsCsvSong[] = []
+
+    // The review CSV names the file each song's lyrics were uploaded from
+    const sourceFilenames = new Map<string, string>()
+    if (includeCsv) {
+      const { data: assets } = await supabase
+        .from('song_assets')
+        .select('song_id, original_filename')
+        .in('song_id', setSongs.map((setSong: { song_id: string }) => setSong.song_id))
+        .eq('asset_type', 'lyrics_source')
+        .order('created_at', { ascending: false })
+      for (const asset of assets ?? []) {
+        if (!sourceFilenames.has(asset.song_id)) {
+          sourceFilenames.set(asset.song_id, asset.original_filename)
+        }
+      }
+    }
+
     const readmeLines = [
       `Set: ${formatDate(set.service_date)}`,
       `Group: ${set.music_groups?.name || 'Unknown'}`,
@@ -93,6 +121,11 @@ export async function POST(
       if (!song) continue
 
       const { slides } = await getSongSlides(song.id, set.group_id)
+      if (includeCsv) {
+        csvSongs.push({ title: song.title, filename: sourceFilenames.get(song.id), slides })
+      }
+      if (perSongFormats.length === 0) continue
+
       let text = await buildLyricsText(slides, setSong.notes)
       if (!text.trim()) {
         text = `Lyrics not available for "${song.title}".\n\nPlease upload lyrics to the song library.`
@@ -101,7 +134,7 @@ export async function POST(
       const position = String(i + 1).padStart(2, '0')
       const safeTitle = sanitizeFilename(song.title)
 
-      for (const format of requestedFormats) {
+      for (const format of perSongFormats) {
         const filename = `${position} - ${safeTitle}.${format}`
         if (format === 'txt') {
           const content = await lyricsToTxt(text)
@@ -161,13 +194,28 @@ export async function POST(
       }
     }
 
+    const safeSetTitle = sanitizeFilename(formatDate(set.service_date))
+
+    if (includeCsv) {
+      const content = await lyricsToCsv(csvSongs)
+      const filename = `${safeSetTitle} - Lyrics Review.csv`
+      if (singleFormat) {
+        return new NextResponse(content, {
+          headers: {
+            'Content-Type': 'text/csv; charset=utf-8',
+            'Content-Disposition': `attachment; filename="${filename}"`,
+          },
+        })
+      }
+      zip.file(filename, content)
+    }
+
     const zipBuffer = await zip.generateAsync({
       type: 'nodebuffer',
       compression: 'DEFLATE',
       compressionOptions: { level: 6 },
     })
 
-    const safeSetTitle = sanitizeFilename(formatDate(set.service_date))
     const zipFilename = `${safeSetTitle} - Lyrics.zip`
 
     return new NextResponse(new Uint8Array(zipBuffer), {
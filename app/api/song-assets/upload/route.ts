@@ -1,6 +1,6 @@
 import { NextRequest, NextResponse } from 'next/server'
 import { createServerSupabaseClient } from '@/lib/supabase/server'
-import { extractText, getSupportedMimeTypes } from '@/lib/extractors'
+import { extractText, getSupportedMimeTypes, getUnusableFileError } from '@/lib/extractors'
 import { createDefaultArrangementFromLyrics } from '@/lib/actions/song-arrangements'
 
 export const runtime = 'nodejs'
@@ -48,6 +48,15 @@ export async function POST(request: NextRequest) {
       )
     }
     
+    // Reject empty uploads and Office lock files before anything is stored
+    const unusableFileError = getUnusableFileError(file.name, file.size)
+    if (unusableFileError) {
+      return NextResponse.json(
+        { error: unusableFileError.message, code: unusableFileError.code },
+        { status: 400 }
+      )
+    }
+    
     const supabase = createServerSupabaseClient()
     
     // Generate a unique storage path
@@ -48,6 +48,7 @@ import {
 } from '@/components/ui/select'
 import { Label } from '@/components/ui/label'
 import { type MusicGroup } from '@/lib/supabase/server'
+import { type UnusableFileCode } from '@/lib/extractors'
 import {
   createSongFromFile,
   checkForDuplicateSong,
@@ -65,6 +66,7 @@ interface QueuedFile {
   file: File
   status: UploadStatus
   error?: string
+  skipReason?: string
   songId?: string
   // Duplicate detection
   extractedText?: string
@@ -92,6 +94,23 @@ interface BulkUploadDialogProps {
 
 const MAX_CONCURRENT_UPLOADS = 5
 
+/**
+ * Lock files are skipped rather than counted as failures, and empty files get
+ * their own message so they aren't mistaken for corrupt uploads
+ */
+function getUnusableFileUpdate(
+  errorCode?: UnusableFileCode
+): Pick<QueuedFile, 'status' | 'error' | 'skipReason'> | null {
+  switch (errorCode) {
+    case 'OFFICE_LOCK_FILE':
+      return { status: 'skipped', skipReason: 'Skipped (Office lock file)' }
+    case 'EMPTY_FILE':
+      return { status: 'error', error: 'File is empty (0 bytes)' }
+    default:
+      return null
+  }
+}
+
 export function BulkUploadDialog({
   groupId,
   groupSlug,
@@ -188,12 +207,12 @@ export function BulkUploadDialog({
         const extractResult = await extractTextFromFile(formData)
 
         if (!extractResult.success) {
+          const update = getUnusableFileUpdate(extractResult.errorCode) ?? {
+            status: 'error' as const,
+            error: extractResult.error,
+          }
           setQueue((prev) =>
-            prev.map((f) =>
-              f.id === queuedFile.id
-                ? { ...f, status: 'error' as const, error: extractResult.error }
-                : f
-            )
+            prev.map((f) => (f.id === queuedFile.id ? { ...f, ...update } : f))
           )
           continue
         }
@@ -377,12 +396,12 @@ export function BulkUploadDialog({
             )
           )
         } else {
+          const update = getUnusableFileUpdate(result.errorCode) ?? {
+            status: 'error' as const,
+            error: result.error || 'Upload failed',
+          }
           setQueue((prev) =>
-            prev.map((f) =>
-              f.id === queuedFile.id
-                ? { ...f, status: 'error' as const, error: result.error || 'Upload failed' }
-                : f
-            )
+            prev.map((f) => (f.id === queuedFile.id ? { ...f, ...update } : f))
           )
         }
       } catch {
@@ -796,7 +815,9 @@ export function BulkUploadDialog({
                           )}
 
                           {queuedFile.status === 'skipped' && (
-                            <p className="text-xs text-muted-foreground">Skipped (duplicate)</p>
+                            <p className="text-xs text-muted-foreground">
+                              {queuedFile.skipReason ?? 'Skipped (duplicate)'}
+                            </p>
                           )}
 
                           {queuedFile.error && (
@@ -8,13 +8,14 @@ import { Checkbox } from '@/components/ui/checkbox'
 import { Dialog, DialogContent, DialogDescription, DialogFooter, DialogHeader, DialogTitle, DialogTrigger } from '@/components/ui/dialog'
 import { FormatToggleCard } from '@/components/format-toggle-card'
 
-type LyricsFormat = 'txt' | 'docx' | 'pdf' | 'rtf'
+type LyricsFormat = 'txt' | 'docx' | 'pdf' | 'rtf' | 'csv'
 
 const DEFAULT_FORMATS: Record<LyricsFormat, boolean> = {
   txt: true,
   docx: true,
   pdf: true,
   rtf: true,
+  csv: false,
 }
 
 const FORMAT_LABELS: Record<LyricsFormat, string> = {
@@ -22,6 +23,7 @@ const FORMAT_LABELS: Record<LyricsFormat, string> = {
   docx: 'DOCX (Word)',
   pdf: 'PDF',
   rtf: 'RTF',
+  csv: 'CSV (review)',
 }
 
 const FORMAT_DETAILS: Record<LyricsFormat, { description: string }> = {
@@ -37,6 +39,9 @@ const FORMAT_DETAILS: Record<LyricsFormat, { description: string }> = {
   rtf: {
     description: 'Basic formatting for older tools.',
   },
+  csv: {
+    description: 'One spreadsheet row per lyric line across the set.',
+  },
 }
 
 interface SetLyricsExportDialogProps {
@@ -102,6 +107,10 @@ export function SetLyricsExportDialog({ setId, setTitle, songs }: SetLyricsExpor
   const isSingleSong = selectedSongIds.length === 1
   const isSingleFormat = selectedFormats.length === 1
   const downloadLabel = useMemo(() => {
+    // The review CSV is a single file for the whole selection
+    if (isSingleFormat && selectedFormats[0] === 'csv') {
+      return 'Download .csv'
+    }
     if (!isSingleSong || !isSingleFormat) {
       return 'Download .zip'
     }
@@ -10,7 +10,7 @@ import {
   type SongSlideGroup,
 } from '@/lib/supabase/server'
 import { extractText } from '@/lib/extractors'
-import { hasLyricGroupHeadings } from '@/lib/song-import'
+import { hasLyricGroupHeadings, normalizeText } from '@/lib/song-import'
 import { createSongRevisionSnapshot } from '@/lib/actions/song-revisions'
 
 const GROUP_KEY_SEPARATOR = '::'
@@ -668,7 +668,7 @@ export async function parseLyricsToSlides(
   text: string,
   options?: { forceUngrouped?: boolean }
 ): Promise<SongSlide[]> {
-  const blocks = text.split(/\n\s*\n/).filter((block) => block.trim())
+  const blocks = normalizeText(text).split(/\n\s*\n/).filter((block) => block.trim())
 
   return blocks.map((block) => {
     const lines = block
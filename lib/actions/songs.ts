@@ -9,7 +9,12 @@ import {
   type SongArrangement,
   type SongAsset,
 } from '@/lib/supabase/server'
-import { extractText } from '@/lib/extractors'
+import {
+  extractText,
+  getUnusableFileError,
+  UnusableFileError,
+  type UnusableFileCode,
+} from '@/lib/extractors'
 import { parseSongImportText } from '@/lib/song-import'
 import { createDefaultArrangementFromLyrics } from '@/lib/actions/song-arrangements'
 import { createSongRevisionSnapshot } from '@/lib/actions/song-revisions'
@@ -438,6 +443,7 @@ export async function extractTextFromFile(
   linkUrl?: string
   hasGroupHeadings?: boolean
   error?: string
+  errorCode?: UnusableFileCode
 }> {
   const file = formData.get('file') as File | null
   
@@ -466,7 +472,8 @@ export async function extractTextFromFile(
     }
   } catch (err) {
     const errorMessage = err instanceof Error ? err.message : 'Failed to extract text'
-    return { success: false, error: errorMessage, title: titleFromFilename }
+    const errorCode = err instanceof UnusableFileError ? err.code : undefined
+    return { success: false, error: errorMessage, errorCode, title: titleFromFilename }
   }
 }
 
@@ -850,7 +857,7 @@ export async function createSong(
   groupId: string,
   groupSlug: string,
   formData: FormData
-): Promise<{ success: boolean; error?: string; song?: Song }> {
+): Promise<{ success: boolean; error?: string; errorCode?: UnusableFileCode; song?: Song }> {
   const title = formData.get('title') as string
   const file = formData.get('file') as File | null
   const lyrics = formData.get('lyrics') as string | null
@@ -860,6 +867,12 @@ export async function createSong(
   const artistRaw = formData.get('artist')
   const linkUrlRaw = formData.get('link_url')
 
+  // Reject empty uploads and Office lock files before any song row is written
+  const unusableFileError = file ? getUnusableFileError(file.name, file.size) : null
+  if (unusableFileError) {
+    return { success: false, error: unusableFileError.message, errorCode: unusableFileError.code }
+  }
+
   let songTitle = title?.trim()
 
   // If no title provided but we have a file, use filename
@@ -1274,13 +1287,19 @@ export async function createSongFromFile(
   groupSlug: string,
   formData: FormData,
   options?: CreateSongFromFileOptions
-): Promise<{ success: boolean; error?: string; song?: Song }> {
+): Promise<{ success: boolean; error?: string; errorCode?: UnusableFileCode; song?: Song }> {
   const file = formData.get('file') as File | null
 
   if (!file) {
     return { success: false, error: 'No file provided' }
   }
 
+  // Reject empty uploads and Office lock files before any song row is written
+  const unusableFileError = getUnusableFileError(file.name, file.size)
+  if (unusableFileError) {
+    return { success: false, error: unusableFileError.message, errorCode: unusableFileError.code }
+  }
+
   // Derive title from filename (remove extension)
   const songTitleFromFilename = file.name.split('.').slice(0, -1).join('.').trim()
   const songTitle = options?.title?.trim() || songTitleFromFilename
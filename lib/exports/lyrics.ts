@@ -92,6 +92,41 @@ function buildVocalGroups(slides: SongSlide[]) {
   return ordered
 }
 
+export type LyricsCsvSong = {
+  title: string
+  filename?: string | null
+  slides: SongSlide[]
+}
+
+function escapeCsv(value: string | number) {
+  const text = String(value)
+  if (/[",\r\n]/.test(text)) {
+    return `"${text.replace(/"/g, '""')}"`
+  }
+  return text
+}
+
+/**
+ * One row per lyric line so a whole set can be reviewed in a spreadsheet
+ */
+export async function lyricsToCsv(songs: LyricsCsvSong[]) {
+  const rows: Array<Array<string | number>> = [['Song', 'Filename', 'Slide', 'Line', 'Text', 'Role']]
+
+  songs.forEach((song) => {
+    song.slides.forEach((slide, slideIndex) => {
+      const role = getGroupDisplayLabel(slide.label, slide.customLabel)
+      const lines = slide.lines ?? []
+      lines.forEach((line, lineIndex) => {
+        if (!line?.trim()) return
+        rows.push([song.title, song.filename ?? '', slideIndex + 1, lineIndex + 1, line, role])
+      })
+    })
+  })
+
+  // Excel only detects UTF-8 (curly quotes, accents) when the file starts with a BOM
+  return `\uFEFF${rows.map((row) => row.map(escapeCsv).join(',')).join('\r\n')}\r\n`
+}
+
 type LyricsLayoutInput = {
   title: string
   songKey?: string | null
@@ -8,6 +8,47 @@ export interface ExtractionResult {
   warning?: string
 }
 
+export type UnusableFileCode = 'EMPTY_FILE' | 'OFFICE_LOCK_FILE'
+
+/**
+ * Thrown for uploads that can never contain lyrics, so callers can report them
+ * as bad input instead of as extraction failures
+ */
+export class UnusableFileError extends Error {
+  readonly code: UnusableFileCode
+
+  constructor(code: UnusableFileCode, message: string) {
+    super(message)
+    this.name = 'UnusableFileError'
+    this.code = code
+  }
+}
+
+/**
+ * Check for empty uploads and Office lock files before storing or extracting.
+ * Returns the error to report, or null if the file can be processed.
+ */
+export function getUnusableFileError(filename: string, size: number): UnusableFileError | null {
+  // Office lock files (e.g. "~$Song.docx") are created while a document is open
+  // and never contain the document itself
+  const basename = filename.split(/[\\/]/).pop() || filename
+  if (basename.startsWith('~$')) {
+    return new UnusableFileError(
+      'OFFICE_LOCK_FILE',
+      `"${basename}" is an Office lock file, not a document. Upload the original file instead.`
+    )
+  }
+
+  if (size === 0) {
+    return new UnusableFileError(
+      'EMPTY_FILE',
+      `"${basename}" is empty (0 bytes). Please re-save the file and try again.`
+    )
+  }
+
+  return null
+}
+
 /**
  * Extract plain text from a file buffer based on its MIME type
  */
@@ -16,6 +57,11 @@ export async function extractText(
   mimeType: string,
   filename: string
 ): Promise<ExtractionResult> {
+  const unusableFileError = getUnusableFileError(filename, buffer.length)
+  if (unusableFileError) {
+    throw unusableFileError
+  }
+
   // Normalize mime type
   const mime = mimeType.toLowerCase()
   const ext = filename.toLowerCase().split('.').pop() || ''
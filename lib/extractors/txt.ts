@@ -25,6 +25,7 @@ export async function extractFromTxt(buffer: Buffer): Promise<{ text: string; wa
 }
 
 function normalizeLineEndings(text: string): string {
-  // Normalize all line endings to \n
-  return text.replace(/\r\n/g, '\n').replace(/\r/g, '\n')
+  // Normalize all line endings to \n. Text copied out of PowerPoint uses a
+  // vertical tab (0x0B) for soft line breaks inside a text box.
+  return text.replace(/\r\n/g, '\n').replace(/[\r\u000B]/g, '\n')
 }
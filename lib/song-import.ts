@@ -24,8 +24,24 @@ export function hasLyricGroupHeadings(lines: string[] | string): boolean {
   })
 }
 
-function normalizeText(text: string) {
-  return text.replace(/\r\n/g, '\n').replace(/\r/g, '\n').replace(/^\uFEFF/, '')
+// NBSP, en/em/thin spaces, ideographic space, etc.
+const UNICODE_SPACE_PATTERN = /[\u00A0\u1680\u2000-\u200A\u202F\u205F\u3000]/g
+// Zero-width space, word joiner, and stray BOMs (ZWJ/ZWNJ are kept for scripts that need them)
+const ZERO_WIDTH_PATTERN = /[\u200B\u2060\uFEFF]/g
+
+/**
+ * Normalize line endings (including vertical tab, form feed, NEL and
+ * U+2028/U+2029) to \n, replace non-ASCII spaces with a plain space, and drop
+ * zero-width characters, which otherwise show up as odd gaps in ProPresenter
+ * or hide blank lines between stanzas
+ */
+export function normalizeText(text: string) {
+  return text
+    .replace(/\r\n/g, '\n')
+    .replace(/\r/g, '\n')
+    .replace(/[\u000B\u000C\u0085\u2028\u2029]/g, '\n')
+    .replace(ZERO_WIDTH_PATTERN, '')
+    .replace(UNICODE_SPACE_PATTERN, ' ')
 }
 
 function isLikelyMetadataLine(line: string) {